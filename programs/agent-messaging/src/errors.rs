@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum MessagingError {
+    #[msg("Message body cannot be empty")]
+    EmptyBody,
+
+    #[msg("Message body is too long (max 1000 characters)")]
+    BodyTooLong,
+
+    #[msg("reply_to does not reference a message in this thread")]
+    ReplyNotInThread,
+
+    #[msg("Reaction messages must reference a reaction_target")]
+    MissingReactionTarget,
+
+    #[msg("Author's agent is not active in the registry")]
+    AuthorNotActive,
+
+    #[msg("Counterparty's agent is not active in the registry")]
+    CounterpartyNotActive,
+
+    #[msg("counterparty_agent does not match this thread")]
+    CounterpartyNotInThread,
+}
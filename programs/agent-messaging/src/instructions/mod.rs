@@ -0,0 +1,3 @@
+pub mod post_message;
+
+pub use post_message::*;
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use agent_registry::state::AgentAccount;
+
+use crate::errors::MessagingError;
+use crate::state::{Message, MessageType};
+
+#[derive(Accounts)]
+#[instruction(thread: Pubkey, body: String, reply_to: Option<Pubkey>, message_type: MessageType, reaction_target: Option<Pubkey>, seq: u64)]
+pub struct PostMessage<'info> {
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// The author's registered agent; must be active and admin'd by `author`
+    #[account(constraint = author_agent.admin == author.key())]
+    pub author_agent: Account<'info, AgentAccount>,
+
+    /// The other party in this thread; a thread is keyed by the counterparty's
+    /// agent account, so this must match `thread` and must be active
+    #[account(constraint = counterparty_agent.key() == thread @ MessagingError::CounterpartyNotInThread)]
+    pub counterparty_agent: Account<'info, AgentAccount>,
+
+    /// The message being replied to, required (and validated) when `reply_to` is `Some`
+    pub reply_to_message: Option<Account<'info, Message>>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + Message::INIT_SPACE,
+        seeds = [b"msg", thread.as_ref(), author.key().as_ref(), &seq.to_le_bytes()],
+        bump
+    )]
+    pub message: Account<'info, Message>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PostMessage>,
+    thread: Pubkey,
+    body: String,
+    reply_to: Option<Pubkey>,
+    message_type: MessageType,
+    reaction_target: Option<Pubkey>,
+    seq: u64,
+) -> Result<()> {
+    require!(!body.is_empty(), MessagingError::EmptyBody);
+    require!(
+        body.len() <= Message::MAX_BODY_LEN,
+        MessagingError::BodyTooLong
+    );
+    require!(
+        ctx.accounts.author_agent.active,
+        MessagingError::AuthorNotActive
+    );
+    require!(
+        ctx.accounts.counterparty_agent.active,
+        MessagingError::CounterpartyNotActive
+    );
+
+    match message_type {
+        MessageType::Reaction => {
+            require!(
+                reaction_target.is_some(),
+                MessagingError::MissingReactionTarget
+            );
+        }
+        MessageType::Text => {}
+    }
+
+    // Note: `reply_to` can never equal this message's own key — `message` is
+    // a freshly `init`'d PDA keyed by `seq`, so no prior message can share its
+    // address — so there is no self-reply case to guard against here.
+    if let Some(reply_to) = reply_to {
+        let reply_to_message = ctx
+            .accounts
+            .reply_to_message
+            .as_ref()
+            .ok_or(MessagingError::ReplyNotInThread)?;
+        require!(
+            reply_to_message.key() == reply_to,
+            MessagingError::ReplyNotInThread
+        );
+        require!(
+            reply_to_message.thread == thread,
+            MessagingError::ReplyNotInThread
+        );
+    }
+
+    let clock = Clock::get()?;
+    let message = &mut ctx.accounts.message;
+
+    message.author = ctx.accounts.author.key();
+    message.thread = thread;
+    message.reply_to = reply_to;
+    message.body = body;
+    message.message_type = message_type;
+    message.reaction_target = reaction_target;
+    message.seq = seq;
+    message.posted_at = clock.unix_timestamp;
+    message.bump = ctx.bumps.message;
+
+    emit!(MessagePostedEvent {
+        message: message.key(),
+        author: message.author,
+        thread,
+        reply_to,
+        message_type,
+        reaction_target,
+        posted_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MessagePostedEvent {
+    pub message: Pubkey,
+    pub author: Pubkey,
+    pub thread: Pubkey,
+    pub reply_to: Option<Pubkey>,
+    pub message_type: MessageType,
+    pub reaction_target: Option<Pubkey>,
+    pub posted_at: i64,
+}
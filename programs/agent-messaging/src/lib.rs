@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+declare_id!("AgentMsg1111111111111111111111111111111111");
+
+pub mod instructions;
+pub mod state;
+pub mod errors;
+
+use instructions::*;
+use state::MessageType;
+
+// Security.txt - Contact information for security researchers
+// This is embedded in the program binary and visible in Solana Explorer
+#[cfg(not(feature = "no-entrypoint"))]
+use solana_security_txt::security_txt;
+
+#[cfg(not(feature = "no-entrypoint"))]
+security_txt! {
+    // Required fields
+    name: "Ordo Agent Messaging",
+    project_url: "https://github.com/agentic-reserve/ORDO",
+    contacts: "email:security@ordo.com,link:https://github.com/agentic-reserve/ORDO/security,discord:Ordo#1234",
+    policy: "https://github.com/agentic-reserve/ORDO/blob/main/SECURITY.md",
+
+    // Optional fields
+    preferred_languages: "en",
+    source_code: "https://github.com/agentic-reserve/ORDO",
+    source_release: env!("CARGO_PKG_VERSION"),
+    auditors: "Pending - Apply for Claude Code Security audit",
+    acknowledgements: "
+Security researchers who help secure Ordo:
+- Report vulnerabilities to security@ordo.com
+- We appreciate responsible disclosure
+"
+}
+
+#[program]
+pub mod agent_messaging {
+    use super::*;
+
+    /// Post a message in an agent-to-agent thread, optionally as a reply
+    /// to an earlier message, or as a reaction naming a `reaction_target`.
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        thread: Pubkey,
+        body: String,
+        reply_to: Option<Pubkey>,
+        message_type: MessageType,
+        reaction_target: Option<Pubkey>,
+        seq: u64,
+    ) -> Result<()> {
+        instructions::post_message::handler(
+            ctx,
+            thread,
+            body,
+            reply_to,
+            message_type,
+            reaction_target,
+            seq,
+        )
+    }
+}
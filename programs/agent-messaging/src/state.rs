@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Kind of content a `Message` carries
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MessageType {
+    /// Free-form text post
+    Text,
+    /// A reaction to another message, identified by `reaction_target`
+    Reaction,
+}
+
+/// Message stores a single durable post in an agent-to-agent thread
+///
+/// PDA seeds: `[b"msg", thread, author, seq]`, so messages from the same
+/// author in the same thread are ordered and individually addressable.
+#[account]
+#[derive(InitSpace)]
+pub struct Message {
+    /// Signer that posted this message (the admin of a registered agent)
+    pub author: Pubkey,
+    /// Conversation this message belongs to
+    pub thread: Pubkey,
+    /// Prior message in the same thread this one replies to, if any
+    pub reply_to: Option<Pubkey>,
+    /// Message body
+    #[max_len(1000)]
+    pub body: String,
+    pub message_type: MessageType,
+    /// Message being reacted to; always set when `message_type` is
+    /// `Reaction`, and stored as given (not checked) for `Text` messages
+    pub reaction_target: Option<Pubkey>,
+    /// Per-author sequence number, used in PDA derivation
+    pub seq: u64,
+    /// Timestamp when the message was posted
+    pub posted_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Message {
+    pub const MAX_BODY_LEN: usize = 1000;
+}
@@ -25,4 +25,22 @@ pub enum AgentRegistryError {
     
     #[msg("Cannot rate yourself")]
     CannotRateSelf,
+
+    #[msg("This action is denied by the group's policy")]
+    PolicyDenied,
+
+    #[msg("Signer is not a member of this permission group")]
+    NotGroupMember,
+
+    #[msg("Signer's role does not meet the required policy for this action")]
+    InsufficientRole,
+
+    #[msg("Permission group is full")]
+    GroupFull,
+
+    #[msg("Member is already part of this permission group")]
+    MemberAlreadyExists,
+
+    #[msg("Member was not found in this permission group")]
+    MemberNotFound,
 }
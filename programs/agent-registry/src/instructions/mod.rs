@@ -0,0 +1,7 @@
+pub mod register_agent;
+pub mod update_agent;
+pub mod update_reputation;
+
+pub use register_agent::*;
+pub use update_agent::*;
+pub use update_reputation::*;
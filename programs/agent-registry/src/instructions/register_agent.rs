@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::AgentAccount;
 use crate::errors::AgentRegistryError;
+use crate::reputation_ring::ReputationRing;
 
 #[derive(Accounts)]
 #[instruction(name: String)]
@@ -8,15 +9,27 @@ pub struct RegisterAgent<'info> {
     #[account(
         init,
         payer = authority,
-        space = AgentAccount::BASE_SIZE + 256, // Allocate reasonable space
+        space = 8 + AgentAccount::INIT_SPACE,
         seeds = [b"agent", authority.key().as_ref()],
         bump
     )]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
+    /// The agent's rolling reputation window, created once here alongside
+    /// the agent account itself so `update_reputation` never needs to
+    /// conditionally initialize it
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReputationRing::INIT_SPACE,
+        seeds = [b"reputation_ring", agent_account.key().as_ref()],
+        bump
+    )]
+    pub reputation_ring: Account<'info, ReputationRing>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
@@ -56,7 +69,8 @@ pub fn handler(
     let agent_account = &mut ctx.accounts.agent_account;
     let clock = Clock::get()?;
 
-    agent_account.authority = ctx.accounts.authority.key();
+    agent_account.creator = ctx.accounts.authority.key();
+    agent_account.admin = ctx.accounts.authority.key();
     agent_account.name = name.clone();
     agent_account.description = description;
     agent_account.agent_uri = agent_uri;
@@ -69,6 +83,10 @@ pub fn handler(
     agent_account.generation = generation;
     agent_account.bump = ctx.bumps.agent_account;
 
+    let reputation_ring = &mut ctx.accounts.reputation_ring;
+    reputation_ring.agent = agent_account.key();
+    reputation_ring.bump = ctx.bumps.reputation_ring;
+
     // Emit event
     emit!(AgentRegisteredEvent {
         agent: ctx.accounts.agent_account.key(),
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::AgentAccount;
+use crate::errors::AgentRegistryError;
+
+#[derive(Accounts)]
+pub struct AgentEdit<'info> {
+    #[account(
+        mut,
+        seeds = [b"agent", agent_account.creator.as_ref()],
+        bump = agent_account.bump,
+        constraint = agent_account.admin == admin.key()
+    )]
+    pub agent_account: Account<'info, AgentAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Patch any subset of an agent's mutable fields. `None` leaves a field
+/// unchanged; `new_admin` transfers operational control without
+/// re-deriving the agent PDA (the PDA stays keyed on the immutable `creator`).
+pub fn handler(
+    ctx: Context<AgentEdit>,
+    name: Option<String>,
+    description: Option<String>,
+    agent_uri: Option<String>,
+    services: Option<Vec<String>>,
+    active: Option<bool>,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    if let Some(name) = &name {
+        require!(
+            name.len() <= AgentAccount::MAX_NAME_LEN,
+            AgentRegistryError::NameTooLong
+        );
+    }
+    if let Some(description) = &description {
+        require!(
+            description.len() <= AgentAccount::MAX_DESCRIPTION_LEN,
+            AgentRegistryError::DescriptionTooLong
+        );
+    }
+    if let Some(agent_uri) = &agent_uri {
+        require!(
+            agent_uri.len() <= AgentAccount::MAX_URI_LEN,
+            AgentRegistryError::UriTooLong
+        );
+    }
+    if let Some(services) = &services {
+        require!(
+            services.len() <= AgentAccount::MAX_SERVICES,
+            AgentRegistryError::TooManyServices
+        );
+        for service in services {
+            require!(
+                service.len() <= AgentAccount::MAX_SERVICE_LEN,
+                AgentRegistryError::ServiceNameTooLong
+            );
+        }
+    }
+
+    let agent_account = &mut ctx.accounts.agent_account;
+
+    if let Some(name) = name {
+        agent_account.name = name;
+    }
+    if let Some(description) = description {
+        agent_account.description = description;
+    }
+    if let Some(agent_uri) = agent_uri {
+        agent_account.agent_uri = agent_uri;
+    }
+    if let Some(services) = services {
+        agent_account.services = services;
+    }
+    if let Some(active) = active {
+        agent_account.active = active;
+    }
+    if let Some(new_admin) = new_admin {
+        agent_account.admin = new_admin;
+    }
+
+    emit!(AgentUpdatedEvent {
+        agent: agent_account.key(),
+        admin: agent_account.admin,
+        active: agent_account.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AgentUpdatedEvent {
+    pub agent: Pubkey,
+    pub admin: Pubkey,
+    pub active: bool,
+    pub timestamp: i64,
+}
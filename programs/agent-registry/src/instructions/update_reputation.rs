@@ -1,25 +1,26 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, ReputationRecord};
+use crate::state::AgentAccount;
 use crate::errors::AgentRegistryError;
+use crate::reputation_ring::{ReputationRing, MAX_COMMENT_LEN};
 
 #[derive(Accounts)]
 pub struct UpdateReputation<'info> {
     #[account(mut)]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
+    /// Rolling window of this agent's most recent ratings, created once by
+    /// `register_agent` and overwritten in place on every rating thereafter.
+    /// This is the only per-rating account update_reputation touches, so
+    /// rent cost for an agent's rating history stays bounded to one account
+    /// no matter how many distinct raters it accumulates.
     #[account(
-        init,
-        payer = rater,
-        space = ReputationRecord::BASE_SIZE + 256, // Allocate reasonable space
-        seeds = [b"reputation", agent_account.key().as_ref(), rater.key().as_ref()],
-        bump
+        mut,
+        seeds = [b"reputation_ring", agent_account.key().as_ref()],
+        bump = reputation_ring.bump,
     )]
-    pub reputation_record: Account<'info, ReputationRecord>,
-    
-    #[account(mut)]
+    pub reputation_ring: Account<'info, ReputationRing>,
+
     pub rater: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -33,38 +34,37 @@ pub fn handler(
         AgentRegistryError::InvalidReputationScore
     );
     require!(
-        comment.len() <= ReputationRecord::MAX_COMMENT_LEN,
+        comment.len() <= MAX_COMMENT_LEN,
         AgentRegistryError::CommentTooLong
     );
     require!(
-        ctx.accounts.agent_account.authority != ctx.accounts.rater.key(),
+        ctx.accounts.agent_account.admin != ctx.accounts.rater.key(),
         AgentRegistryError::CannotRateSelf
     );
 
-    let reputation_record = &mut ctx.accounts.reputation_record;
     let agent_account = &mut ctx.accounts.agent_account;
+    let reputation_ring = &mut ctx.accounts.reputation_ring;
     let clock = Clock::get()?;
 
-    // Store reputation record
-    reputation_record.agent = ctx.accounts.agent_account.key();
-    reputation_record.rater = ctx.accounts.rater.key();
-    reputation_record.score = score;
-    reputation_record.comment = comment.clone();
-    reputation_record.timestamp = clock.unix_timestamp;
-    reputation_record.bump = ctx.bumps.reputation_record;
-
     // Update cumulative reputation score on agent account
     agent_account.reputation_score = agent_account
         .reputation_score
         .checked_add(score)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
-    // Emit event
+    // Record into the rolling window and recompute the windowed average
+    reputation_ring.record(ctx.accounts.rater.key(), score, clock.unix_timestamp);
+
+    // Emit event; `comment` is not stored on-chain (see `ReputationRing`
+    // doc comment) so this event is the durable record off-chain indexers
+    // should index for full-text history.
     emit!(ReputationUpdatedEvent {
         agent: ctx.accounts.agent_account.key(),
         rater: ctx.accounts.rater.key(),
         score,
+        comment,
         new_total_score: agent_account.reputation_score,
+        windowed_score: reputation_ring.reputation_score,
         timestamp: clock.unix_timestamp,
     });
 
@@ -76,6 +76,8 @@ pub struct ReputationUpdatedEvent {
     pub agent: Pubkey,
     pub rater: Pubkey,
     pub score: i64,
+    pub comment: String,
     pub new_total_score: i64,
+    pub windowed_score: i64,
     pub timestamp: i64,
 }
@@ -5,8 +5,11 @@ declare_id!("AgentReg11111111111111111111111111111111111");
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod permission_hooks;
+pub mod reputation_ring;
 
 use instructions::*;
+use permission_hooks::{GroupAction, GroupMember, MemberRole, PermissionPolicy};
 
 // Security.txt - Contact information for security researchers
 // This is embedded in the program binary and visible in Solana Explorer
@@ -64,4 +67,71 @@ pub mod agent_registry {
     ) -> Result<()> {
         instructions::update_reputation::handler(ctx, score, comment)
     }
+
+    pub fn update_agent(
+        ctx: Context<AgentEdit>,
+        name: Option<String>,
+        description: Option<String>,
+        agent_uri: Option<String>,
+        services: Option<Vec<String>>,
+        active: Option<bool>,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_agent::handler(
+            ctx,
+            name,
+            description,
+            agent_uri,
+            services,
+            active,
+            new_admin,
+        )
+    }
+
+    pub fn create_agent_permission(
+        ctx: Context<permission_hooks::CreateAgentPermission>,
+        group_id: Pubkey,
+        members: Vec<GroupMember>,
+    ) -> Result<()> {
+        permission_hooks::create_agent_permission(ctx, group_id, members)
+    }
+
+    pub fn add_group_member(
+        ctx: Context<permission_hooks::AddGroupMember>,
+        new_member: Pubkey,
+        role: MemberRole,
+    ) -> Result<()> {
+        permission_hooks::add_group_member(ctx, new_member, role)
+    }
+
+    pub fn remove_group_member(
+        ctx: Context<permission_hooks::RemoveGroupMember>,
+        member_to_remove: Pubkey,
+    ) -> Result<()> {
+        permission_hooks::remove_group_member(ctx, member_to_remove)
+    }
+
+    pub fn change_group_policy(
+        ctx: Context<permission_hooks::ChangeGroupPolicy>,
+        action: GroupAction,
+        new_policy: PermissionPolicy,
+    ) -> Result<()> {
+        permission_hooks::change_group_policy(ctx, action, new_policy)
+    }
+
+    pub fn change_member_role(
+        ctx: Context<permission_hooks::ChangeMemberRole>,
+        member: Pubkey,
+        new_role: MemberRole,
+    ) -> Result<()> {
+        permission_hooks::change_member_role(ctx, member, new_role)
+    }
+
+    pub fn create_private_agent(
+        ctx: Context<permission_hooks::CreatePrivateAgent>,
+        name: String,
+        initial_balance: u64,
+    ) -> Result<()> {
+        permission_hooks::create_private_agent(ctx, name, initial_balance)
+    }
 }
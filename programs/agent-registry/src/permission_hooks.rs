@@ -1,21 +1,158 @@
 /**
  * MagicBlock Permission Hooks for Agent Registry
- * 
+ *
  * Implements permission groups and access control using MagicBlock's Permission Program
  * This allows agents to have private state that only authorized parties can access
  */
 
 use anchor_lang::prelude::*;
 use magicblock_permission_client::instructions::{
-    CreateGroupCpiBuilder, CreatePermissionCpiBuilder,
+    AddMemberCpiBuilder, CreateGroupCpiBuilder, CreatePermissionCpiBuilder, RemoveMemberCpiBuilder,
 };
 
+use crate::errors::AgentRegistryError;
+
 // Seed for agent account PDAs
 pub const AGENT_PDA_SEED: &[u8] = b"agent";
+// Seed for the policy set PDA sibling to a permission group
+pub const POLICY_PDA_SEED: &[u8] = b"policy";
+// Maximum number of members tracked per policy set
+pub const MAX_POLICY_MEMBERS: usize = 20;
+
+/**
+ * Action a member may attempt against a permission group
+ *
+ * Each variant maps to exactly one slot in `PolicySet`, so resolving the
+ * governing `PermissionPolicy` for an instruction is a single field lookup.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum GroupAction {
+    AddMember,
+    RemoveMember,
+    UpdateMetadata,
+    ChangePolicy,
+    ChangeRole,
+}
+
+/**
+ * Policy governing who may perform a given `GroupAction`
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PermissionPolicy {
+    /// Any member of the group may perform the action
+    Allow,
+    /// The action is rejected unconditionally, regardless of role
+    Deny,
+    /// Only admins (or the super-admin) may perform the action
+    Admin,
+    /// Only the group's super-admin may perform the action
+    SuperAdmin,
+}
+
+/**
+ * Role held by a member within a permission group
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MemberRole {
+    Regular,
+    Admin,
+    SuperAdmin,
+}
+
+/// A single member entry tracked on `PolicySet`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct GroupMember {
+    pub member: Pubkey,
+    pub role: MemberRole,
+}
+
+/**
+ * PolicySet stores the role-based access rules for a single permission group
+ *
+ * It is a sibling PDA to the MagicBlock permission `group` account, keyed by
+ * the same `group_id` so the two stay in lock-step. This is what
+ * `add_group_member` / `remove_group_member` / `change_group_policy` consult
+ * before they ever touch the underlying MagicBlock CPI.
+ */
+#[account]
+#[derive(InitSpace)]
+pub struct PolicySet {
+    /// Id of the MagicBlock permission group this policy set governs; also
+    /// the seed this policy set's PDA is derived from
+    pub group: Pubkey,
+    /// The agent account this policy set was created for
+    pub agent_account: Pubkey,
+    /// Super-admin for this group; satisfies every policy except `Deny`
+    pub super_admin: Pubkey,
+    /// Members tracked for role resolution
+    #[max_len(20)]
+    pub members: Vec<GroupMember>,
+    pub add_member_policy: PermissionPolicy,
+    pub remove_member_policy: PermissionPolicy,
+    pub update_metadata_policy: PermissionPolicy,
+    pub change_policy_policy: PermissionPolicy,
+    pub change_role_policy: PermissionPolicy,
+    pub bump: u8,
+}
+
+impl PolicySet {
+    /// Policy governing `action`
+    pub fn policy_for(&self, action: GroupAction) -> PermissionPolicy {
+        match action {
+            GroupAction::AddMember => self.add_member_policy,
+            GroupAction::RemoveMember => self.remove_member_policy,
+            GroupAction::UpdateMetadata => self.update_metadata_policy,
+            GroupAction::ChangePolicy => self.change_policy_policy,
+            GroupAction::ChangeRole => self.change_role_policy,
+        }
+    }
+
+    /// Role held by `signer`, if they are a member of this group
+    pub fn role_of(&self, signer: &Pubkey) -> Option<MemberRole> {
+        if signer == &self.super_admin {
+            return Some(MemberRole::SuperAdmin);
+        }
+        self.members
+            .iter()
+            .find(|m| &m.member == signer)
+            .map(|m| m.role)
+    }
+
+    /// Check that `signer` is authorized to perform `action` under this policy set
+    pub fn enforce(&self, signer: &Pubkey, action: GroupAction) -> Result<()> {
+        match self.policy_for(action) {
+            PermissionPolicy::Deny => Err(AgentRegistryError::PolicyDenied.into()),
+            PermissionPolicy::Allow => {
+                require!(
+                    self.role_of(signer).is_some(),
+                    AgentRegistryError::NotGroupMember
+                );
+                Ok(())
+            }
+            PermissionPolicy::Admin => {
+                let role = self
+                    .role_of(signer)
+                    .ok_or(AgentRegistryError::NotGroupMember)?;
+                require!(
+                    role == MemberRole::Admin || role == MemberRole::SuperAdmin,
+                    AgentRegistryError::InsufficientRole
+                );
+                Ok(())
+            }
+            PermissionPolicy::SuperAdmin => {
+                require!(
+                    signer == &self.super_admin,
+                    AgentRegistryError::InsufficientRole
+                );
+                Ok(())
+            }
+        }
+    }
+}
 
 /**
  * Create a permission group for an agent
- * 
+ *
  * This allows the agent to control who can read their private state
  * Groups can have multiple members and permissions can be modified in a single transaction
  */
@@ -24,31 +161,42 @@ pub const AGENT_PDA_SEED: &[u8] = b"agent";
 pub struct CreateAgentPermission<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
     /// The agent owner who controls permissions
     pub agent_owner: Signer<'info>,
-    
+
     /// The agent account (PDA)
     #[account(
         seeds = [AGENT_PDA_SEED, agent_owner.key().as_ref()],
         bump
     )]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
+    /// The policy set governing the group being created here; the agent
+    /// owner starts out as the group's super-admin
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PolicySet::INIT_SPACE,
+        seeds = [POLICY_PDA_SEED, group_id.as_ref()],
+        bump
+    )]
+    pub policy_set: Account<'info, PolicySet>,
+
     /// Permission account (created by permission program)
     /// CHECK: Checked by the permission program
     #[account(mut)]
     pub permission: UncheckedAccount<'info>,
-    
+
     /// Permission group account (created by permission program)
     /// CHECK: Checked by the permission program
     #[account(mut)]
     pub group: UncheckedAccount<'info>,
-    
+
     /// MagicBlock Permission Program
     /// CHECK: Checked by the permission program
     pub permission_program: UncheckedAccount<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
@@ -56,8 +204,10 @@ pub struct CreateAgentPermission<'info> {
  * Agent account structure
  */
 #[account]
+#[derive(InitSpace)]
 pub struct AgentAccount {
     pub owner: Pubkey,
+    #[max_len(50)]
     pub name: String,
     pub balance: u64,
     pub generation: u32,
@@ -67,33 +217,43 @@ pub struct AgentAccount {
 
 /**
  * Create permission group and permissions for an agent
- * 
+ *
  * This function:
- * 1. Creates a permission group with specified members
+ * 1. Creates a permission group with specified members (each with an
+ *    explicit role, so Admins can be assigned from the start)
  * 2. Creates permissions linking the agent account to the group
  * 3. Stores the group ID in the agent account for reference
+ * 4. Initializes the group's policy set, making the agent owner super-admin
  */
 pub fn create_agent_permission(
     ctx: Context<CreateAgentPermission>,
     group_id: Pubkey,
-    members: Vec<Pubkey>,
+    members: Vec<GroupMember>,
 ) -> Result<()> {
+    require!(
+        members.len() <= MAX_POLICY_MEMBERS,
+        AgentRegistryError::GroupFull
+    );
+
     let CreateAgentPermission {
         payer,
         agent_owner,
         agent_account,
+        policy_set,
         permission,
         permission_program,
         group,
         system_program,
     } = ctx.accounts;
 
+    let member_keys: Vec<Pubkey> = members.iter().map(|m| m.member).collect();
+
     // [1] Create a Permission Group
     // This group will contain all members who can access the agent's private state
     CreateGroupCpiBuilder::new(&permission_program)
         .group(&group)
         .id(group_id)
-        .members(members)
+        .members(member_keys)
         .payer(&payer)
         .system_program(system_program)
         .invoke()?;
@@ -121,6 +281,22 @@ pub fn create_agent_permission(
     let agent = &mut ctx.accounts.agent_account;
     agent.permission_group = Some(group_id);
 
+    // [4] Initialize the policy set: the agent owner is super-admin, each
+    // initial member keeps the role the caller assigned it, member and
+    // metadata actions default to Admin-gated, and policy and role changes
+    // to SuperAdmin-gated, so access can't be widened without an explicit
+    // policy change
+    policy_set.group = group_id;
+    policy_set.agent_account = agent.key();
+    policy_set.super_admin = agent_owner.key();
+    policy_set.members = members;
+    policy_set.add_member_policy = PermissionPolicy::Admin;
+    policy_set.remove_member_policy = PermissionPolicy::Admin;
+    policy_set.update_metadata_policy = PermissionPolicy::Admin;
+    policy_set.change_policy_policy = PermissionPolicy::SuperAdmin;
+    policy_set.change_role_policy = PermissionPolicy::SuperAdmin;
+    policy_set.bump = ctx.bumps.policy_set;
+
     Ok(())
 }
 
@@ -131,44 +307,81 @@ pub fn create_agent_permission(
 pub struct AddGroupMember<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
+    /// The member attempting the add; checked against the policy set's roles
+    pub signer: Signer<'info>,
+
     /// The agent owner who controls permissions
-    pub agent_owner: Signer<'info>,
-    
+    /// CHECK: only used to derive the agent account PDA
+    pub agent_owner: UncheckedAccount<'info>,
+
     /// The agent account (PDA)
     #[account(
         seeds = [AGENT_PDA_SEED, agent_owner.key().as_ref()],
         bump,
-        constraint = agent_account.owner == agent_owner.key()
     )]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
+    #[account(
+        mut,
+        seeds = [POLICY_PDA_SEED, policy_set.group.as_ref()],
+        bump = policy_set.bump,
+        constraint = policy_set.agent_account == agent_account.key()
+    )]
+    pub policy_set: Account<'info, PolicySet>,
+
     /// Permission group account
     /// CHECK: Checked by the permission program
     #[account(mut)]
     pub group: UncheckedAccount<'info>,
-    
+
     /// MagicBlock Permission Program
     /// CHECK: Checked by the permission program
     pub permission_program: UncheckedAccount<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
 /**
  * Add a new member to the agent's permission group
- * 
- * This allows granting access to additional parties without recreating the group
+ *
+ * This allows granting access to additional parties without recreating the
+ * group, at whatever `role` the caller chooses (subject to the `AddMember`
+ * policy the signer must already satisfy). Requires the signer's role to
+ * satisfy the group's `add_member_policy`.
  */
 pub fn add_group_member(
     ctx: Context<AddGroupMember>,
     new_member: Pubkey,
+    role: MemberRole,
 ) -> Result<()> {
-    // Use the permission program's add member instruction
-    // (This would use AddMemberCpiBuilder from the SDK)
-    
+    let policy_set = &mut ctx.accounts.policy_set;
+
+    policy_set.enforce(&ctx.accounts.signer.key(), GroupAction::AddMember)?;
+
+    require!(
+        policy_set.role_of(&new_member).is_none(),
+        AgentRegistryError::MemberAlreadyExists
+    );
+    require!(
+        policy_set.members.len() < MAX_POLICY_MEMBERS,
+        AgentRegistryError::GroupFull
+    );
+
+    AddMemberCpiBuilder::new(&ctx.accounts.permission_program)
+        .group(&ctx.accounts.group)
+        .member(new_member)
+        .payer(&ctx.accounts.payer)
+        .system_program(&ctx.accounts.system_program)
+        .invoke()?;
+
+    policy_set.members.push(GroupMember {
+        member: new_member,
+        role,
+    });
+
     msg!("Added member {} to permission group", new_member);
-    
+
     Ok(())
 }
 
@@ -179,68 +392,182 @@ pub fn add_group_member(
 pub struct RemoveGroupMember<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
+    /// The member attempting the removal; checked against the policy set's roles
+    pub signer: Signer<'info>,
+
     /// The agent owner who controls permissions
-    pub agent_owner: Signer<'info>,
-    
+    /// CHECK: only used to derive the agent account PDA
+    pub agent_owner: UncheckedAccount<'info>,
+
     /// The agent account (PDA)
     #[account(
         seeds = [AGENT_PDA_SEED, agent_owner.key().as_ref()],
         bump,
-        constraint = agent_account.owner == agent_owner.key()
     )]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
+    #[account(
+        mut,
+        seeds = [POLICY_PDA_SEED, policy_set.group.as_ref()],
+        bump = policy_set.bump,
+        constraint = policy_set.agent_account == agent_account.key()
+    )]
+    pub policy_set: Account<'info, PolicySet>,
+
     /// Permission group account
     /// CHECK: Checked by the permission program
     #[account(mut)]
     pub group: UncheckedAccount<'info>,
-    
+
     /// MagicBlock Permission Program
     /// CHECK: Checked by the permission program
     pub permission_program: UncheckedAccount<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
 /**
  * Remove a member from the agent's permission group
- * 
- * This revokes access for a specific party
+ *
+ * This revokes access for a specific party. Requires the signer's role to
+ * satisfy the group's `remove_member_policy`.
  */
-pub fn remove_group_member(
-    ctx: Context<RemoveGroupMember>,
-    member_to_remove: Pubkey,
-) -> Result<()> {
-    // Use the permission program's remove member instruction
-    // (This would use RemoveMemberCpiBuilder from the SDK)
-    
+pub fn remove_group_member(ctx: Context<RemoveGroupMember>, member_to_remove: Pubkey) -> Result<()> {
+    let policy_set = &mut ctx.accounts.policy_set;
+
+    policy_set.enforce(&ctx.accounts.signer.key(), GroupAction::RemoveMember)?;
+
+    let member_index = policy_set
+        .members
+        .iter()
+        .position(|m| m.member == member_to_remove)
+        .ok_or(AgentRegistryError::MemberNotFound)?;
+
+    RemoveMemberCpiBuilder::new(&ctx.accounts.permission_program)
+        .group(&ctx.accounts.group)
+        .member(member_to_remove)
+        .payer(&ctx.accounts.payer)
+        .system_program(&ctx.accounts.system_program)
+        .invoke()?;
+
+    policy_set.members.remove(member_index);
+
     msg!("Removed member {} from permission group", member_to_remove);
-    
+
+    Ok(())
+}
+
+/**
+ * Change the policy governing a single group action
+ */
+#[derive(Accounts)]
+pub struct ChangeGroupPolicy<'info> {
+    /// The member attempting the policy change; checked against `change_policy_policy`
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POLICY_PDA_SEED, policy_set.group.as_ref()],
+        bump = policy_set.bump,
+    )]
+    pub policy_set: Account<'info, PolicySet>,
+}
+
+/**
+ * Re-point one `GroupAction` at a new `PermissionPolicy`
+ *
+ * Gated behind `change_policy_policy`, which defaults to `SuperAdmin` so a
+ * single compromised admin cannot widen their own access.
+ */
+pub fn change_group_policy(
+    ctx: Context<ChangeGroupPolicy>,
+    action: GroupAction,
+    new_policy: PermissionPolicy,
+) -> Result<()> {
+    let policy_set = &mut ctx.accounts.policy_set;
+
+    policy_set.enforce(&ctx.accounts.signer.key(), GroupAction::ChangePolicy)?;
+
+    match action {
+        GroupAction::AddMember => policy_set.add_member_policy = new_policy,
+        GroupAction::RemoveMember => policy_set.remove_member_policy = new_policy,
+        GroupAction::UpdateMetadata => policy_set.update_metadata_policy = new_policy,
+        GroupAction::ChangePolicy => policy_set.change_policy_policy = new_policy,
+        GroupAction::ChangeRole => policy_set.change_role_policy = new_policy,
+    }
+
+    msg!("Updated policy for {:?}", action);
+
+    Ok(())
+}
+
+/**
+ * Change a member's role within a permission group
+ */
+#[derive(Accounts)]
+pub struct ChangeMemberRole<'info> {
+    /// The member attempting the role change; checked against `change_role_policy`
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POLICY_PDA_SEED, policy_set.group.as_ref()],
+        bump = policy_set.bump,
+    )]
+    pub policy_set: Account<'info, PolicySet>,
+}
+
+/**
+ * Promote or demote an existing member to `new_role`
+ *
+ * Gated behind `change_role_policy`, which defaults to `SuperAdmin` so that
+ * changing an existing member's role requires super-admin sign-off. New
+ * members can also be given any role directly through `add_group_member`,
+ * which is gated by `add_member_policy` instead.
+ */
+pub fn change_member_role(
+    ctx: Context<ChangeMemberRole>,
+    member: Pubkey,
+    new_role: MemberRole,
+) -> Result<()> {
+    let policy_set = &mut ctx.accounts.policy_set;
+
+    policy_set.enforce(&ctx.accounts.signer.key(), GroupAction::ChangeRole)?;
+
+    let entry = policy_set
+        .members
+        .iter_mut()
+        .find(|m| m.member == member)
+        .ok_or(AgentRegistryError::MemberNotFound)?;
+    entry.role = new_role;
+
+    msg!("Updated role for {} to {:?}", member, new_role);
+
     Ok(())
 }
 
 /**
  * Example: Create agent with private state
- * 
+ *
  * This shows how to create an agent account and immediately set up permissions
  */
 #[derive(Accounts)]
 pub struct CreatePrivateAgent<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
-    
+
     pub agent_owner: Signer<'info>,
-    
+
     #[account(
         init,
         payer = payer,
-        space = 8 + 32 + 64 + 8 + 4 + 8 + 33, // discriminator + owner + name + balance + generation + created_at + option<pubkey>
+        space = 8 + AgentAccount::INIT_SPACE,
         seeds = [AGENT_PDA_SEED, agent_owner.key().as_ref()],
         bump
     )]
     pub agent_account: Account<'info, AgentAccount>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
@@ -256,8 +583,8 @@ pub fn create_private_agent(
     agent.generation = 0;
     agent.created_at = Clock::get()?.unix_timestamp;
     agent.permission_group = None; // Will be set when permissions are created
-    
+
     msg!("Created private agent: {}", agent.name);
-    
+
     Ok(())
 }
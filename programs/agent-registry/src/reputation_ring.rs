@@ -0,0 +1,95 @@
+/**
+ * Append-only ring buffer for reputation history
+ *
+ * `update_reputation` previously kept only a cumulative sum on `AgentAccount`
+ * and spawned one `ReputationRecord` PDA per (agent, rater) pair, so the raw
+ * ratings were never queryable together and history grew unbounded in
+ * account count. `ReputationRing` replaces that per-rater PDA outright: it
+ * is the single fixed-capacity account attached to each agent that stores
+ * the most recent `CAPACITY` ratings and a rolling average over that
+ * window, so rent cost for an agent's rating history is bounded to this one
+ * account regardless of how many raters it accumulates. Full-text comments
+ * are not retained on-chain here — they're carried in `ReputationUpdatedEvent`
+ * for off-chain indexers, which is where unbounded-length history belongs.
+ */
+
+use anchor_lang::prelude::*;
+
+/// A single rating recorded in a `ReputationRing`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ReputationEntry {
+    pub rater: Pubkey,
+    pub score: i64,
+    pub timestamp: i64,
+}
+
+/// Serialized size of a `ReputationEntry`: 32 (rater) + 8 (score) + 8 (timestamp)
+pub const REPUTATION_ENTRY_SIZE: usize = 32 + 8 + 8;
+
+/// Maximum length of the `comment` argument accepted by `update_reputation`
+pub const MAX_COMMENT_LEN: usize = 500;
+
+/// Declares a fixed-capacity `ReputationRing` account type
+///
+/// Rust can't express a generic array length on an `#[account]` struct
+/// ergonomically, so instead of one generic type we generate a concrete
+/// struct/impl pair per `(name, capacity)`. `INIT_SPACE` is derived from
+/// `ITEM_SIZE` and `CAPACITY` the same way `#[derive(InitSpace)]` derives a
+/// `Vec`'s space, just computed by hand for the fixed-size array case.
+macro_rules! impl_reputation_ring {
+    ($name:ident, $capacity:expr) => {
+        #[account]
+        pub struct $name {
+            /// Agent this ring belongs to
+            pub agent: Pubkey,
+            /// Next slot index to write, modulo `CAPACITY`
+            pub head: u16,
+            /// Number of slots populated so far (saturates at `CAPACITY`)
+            pub len: u16,
+            /// Rolling average score over the live window
+            pub reputation_score: i64,
+            /// Ring storage, oldest entries overwritten once full
+            pub slots: [ReputationEntry; $capacity],
+            /// Bump seed for PDA derivation
+            pub bump: u8,
+        }
+
+        impl $name {
+            pub const CAPACITY: usize = $capacity;
+            pub const ITEM_SIZE: usize = REPUTATION_ENTRY_SIZE;
+
+            /// 32 (agent) + 2 (head) + 2 (len) + 8 (reputation_score)
+            /// + ITEM_SIZE * CAPACITY (slots) + 1 (bump)
+            pub const INIT_SPACE: usize =
+                32 + 2 + 2 + 8 + (Self::ITEM_SIZE * Self::CAPACITY) + 1;
+
+            /// Record a new rating, overwriting the oldest entry once full,
+            /// and recompute the rolling average.
+            pub fn record(&mut self, rater: Pubkey, score: i64, timestamp: i64) {
+                let idx = (self.head as usize) % Self::CAPACITY;
+                self.slots[idx] = ReputationEntry {
+                    rater,
+                    score,
+                    timestamp,
+                };
+                self.head = self.head.wrapping_add(1);
+                if (self.len as usize) < Self::CAPACITY {
+                    self.len += 1;
+                }
+                self.recompute();
+            }
+
+            fn recompute(&mut self) {
+                let count = self.len as usize;
+                if count == 0 {
+                    self.reputation_score = 0;
+                    return;
+                }
+                let sum: i64 = self.slots[..count].iter().map(|entry| entry.score).sum();
+                self.reputation_score = sum / count as i64;
+            }
+        }
+    };
+}
+
+impl_reputation_ring!(ReputationRing, 32);
@@ -2,16 +2,25 @@ use anchor_lang::prelude::*;
 
 /// AgentAccount stores on-chain metadata for each agent
 #[account]
+#[derive(InitSpace)]
 pub struct AgentAccount {
-    /// Authority (owner) of this agent account
-    pub authority: Pubkey,
+    /// Immutable creator of this agent account, set at registration and
+    /// never reassigned; the PDA is derived from this key
+    pub creator: Pubkey,
+    /// Current operational authority; may be reassigned via `update_agent`
+    /// to transfer control without re-deriving the agent PDA
+    pub admin: Pubkey,
     /// Agent name
+    #[max_len(50)]
     pub name: String,
     /// Agent description
+    #[max_len(200)]
     pub description: String,
     /// URI pointing to additional agent metadata
+    #[max_len(200)]
     pub agent_uri: String,
     /// Services offered by this agent
+    #[max_len(10, 50)]
     pub services: Vec<String>,
     /// Whether agent supports x402 payment protocol
     pub x402_support: bool,
@@ -30,51 +39,14 @@ pub struct AgentAccount {
 }
 
 impl AgentAccount {
-    /// Calculate space needed for AgentAccount
-    /// 8 (discriminator) + 32 (authority) + 4 + name.len() + 4 + description.len() 
-    /// + 4 + agent_uri.len() + 4 + services_total_len + 1 (x402_support) + 1 (active)
-    /// + 1 + 32 (parent_agent Option) + 8 (registered_at) + 8 (reputation_score) 
-    /// + 4 (generation) + 1 (bump)
-    pub const BASE_SIZE: usize = 8 + 32 + 4 + 4 + 4 + 4 + 1 + 1 + 1 + 32 + 8 + 8 + 4 + 1;
-    
     /// Maximum size for dynamic fields
+    ///
+    /// These must match the `#[max_len(..)]` attributes above so that the
+    /// `InitSpace`-derived `AgentAccount::INIT_SPACE` and the handler-side
+    /// length validation never drift apart.
     pub const MAX_NAME_LEN: usize = 50;
     pub const MAX_DESCRIPTION_LEN: usize = 200;
     pub const MAX_URI_LEN: usize = 200;
     pub const MAX_SERVICES: usize = 10;
     pub const MAX_SERVICE_LEN: usize = 50;
-    
-    pub fn space(name: &str, description: &str, agent_uri: &str, services: &[String]) -> usize {
-        Self::BASE_SIZE 
-            + name.len() 
-            + description.len() 
-            + agent_uri.len()
-            + services.iter().map(|s| 4 + s.len()).sum::<usize>()
-    }
-}
-
-/// ReputationRecord stores individual reputation ratings
-#[account]
-pub struct ReputationRecord {
-    /// Agent being rated
-    pub agent: Pubkey,
-    /// Rater (who gave this rating)
-    pub rater: Pubkey,
-    /// Reputation score (-100 to +100)
-    pub score: i64,
-    /// Optional comment
-    pub comment: String,
-    /// Timestamp of rating
-    pub timestamp: i64,
-    /// Bump seed for PDA derivation
-    pub bump: u8,
-}
-
-impl ReputationRecord {
-    pub const BASE_SIZE: usize = 8 + 32 + 32 + 8 + 4 + 8 + 1;
-    pub const MAX_COMMENT_LEN: usize = 500;
-    
-    pub fn space(comment: &str) -> usize {
-        Self::BASE_SIZE + comment.len()
-    }
 }